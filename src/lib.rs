@@ -1,15 +1,27 @@
 #![no_std]
 
 use core::mem::size_of;
-use core::cmp::min;
+use core::cmp::{min, max};
 use core::ptr;
+use core::alloc::{GlobalAlloc, Layout};
 
 /// Defines the minimum payload of a chunk excluding the header size as number of platform's alignment unit.
-pub const MIN_PAYLOAD_LEN: usize = 1;
+///
+/// A free chunk embeds the forward & backward pointers of its bin's doubly-linked list in its
+/// payload, so the payload must hold at least two pointers: on a 64-bit target one alignment unit
+/// is a single pointer, hence two units are required.
+pub const MIN_PAYLOAD_LEN: usize = 2;
 
 /// Defines the maximum chunk size including header & payload in platform's alignment unit.
 pub const MAX_CHUNK_SIZE: usize = 0x7FFF;
 
+/// Number of small bins, each holding free chunks of one exact size spaced a single alignment unit
+/// apart starting at `Chunk::min_size()`.
+const NSMALLBINS: usize = 32;
+
+/// Number of tree bins holding the larger free chunks keyed on the high bits of their size.
+const NTREEBINS: usize = 32;
+
 const FLAG_ALLOCATED: u16 = 0x8000;
 const FLAG_LAST: u16 = 0x8000;
 
@@ -116,14 +128,34 @@ impl Chunk {
 
 pub struct Heap<'a> {
     heap: &'a mut [u8],
-    chunk_count: usize
+    chunk_count: usize,
+    /// Heads of the small bins. A null pointer marks an empty bin.
+    smallbins: [*mut Chunk; NSMALLBINS],
+    /// Roots of the tree bins. A null pointer marks an empty bin.
+    treebins: [*mut Chunk; NTREEBINS],
+    /// Bit `i` is set iff small bin `i` is non-empty.
+    smallmap: u32,
+    /// Bit `i` is set iff tree bin `i` is non-empty.
+    treemap: u32,
+    /// The designated victim: a split remainder held out of the bins so repeated same-size
+    /// requests can be carved from it directly; a later remainder replaces it only when larger.
+    /// Null when there is none.
+    dv: *mut Chunk,
+    /// Size of the designated victim in alignment units.
+    dvsize: usize
 }
 
 impl<'a> Heap<'a> {
     pub fn new(heap: &'a mut [u8]) -> Heap {
         let mut h = Heap {
             heap: heap,
-            chunk_count: 0
+            chunk_count: 0,
+            smallbins: [ptr::null_mut(); NSMALLBINS],
+            treebins: [ptr::null_mut(); NTREEBINS],
+            smallmap: 0,
+            treemap: 0,
+            dv: ptr::null_mut(),
+            dvsize: 0
         };
 
         let mut alignment_unit_count = h.heap.len() / Chunk::alignment();
@@ -152,6 +184,16 @@ impl<'a> Heap<'a> {
         }
         c.set_is_last(true);
 
+        // seed the bins with the free chunks we just carved.
+        let mut c = h.first_chunk();
+        loop {
+            h.insert_chunk(c);
+            c = match c.next() {
+                Some(chunk) => chunk,
+                None => break
+            }
+        }
+
         h
     }
 
@@ -234,14 +276,641 @@ impl<'a> Heap<'a> {
     }
 
     pub fn find<'b>(&mut self, size: usize) -> Option<&'b mut Chunk> {
+        let c = self.find_chunk(size);
+        if c.is_null() {
+            return None
+        }
+        Some(unsafe { &mut *c })
+    }
+
+    /// Walk the boundary-tag chain from the first chunk, yielding the size, allocation state and
+    /// payload pointer of each region in address order.
+    pub fn chunks(&self) -> Chunks {
+        Chunks {
+            current: self.first_chunk(),
+            done: false
+        }
+    }
+
+    /// Check the invariants the allocator relies on but never verifies at runtime. Intended for
+    /// tests and debug builds over the unsafe pointer-casting API.
+    pub fn validate(&self) -> Result<(), HeapError> {
+        let mut count = 0;
+        let mut prev: Option<&Chunk> = None;
         let mut c = self.first_chunk();
-        while c.size() < size || c.is_allocated() {
-            c = match c.next() {
-                Some(chunk) => chunk,
-                None => return None
+        loop {
+            count += 1;
+            if count > self.chunk_count {
+                // walked past the recorded length without meeting the last chunk.
+                return Err(HeapError::MissingLast)
+            }
+
+            let expected_prev_size = match prev {
+                Some(p) => p.size(),
+                None => 0
+            };
+            if c.prev_size() != expected_prev_size {
+                return Err(HeapError::PrevSizeMismatch)
+            }
+
+            if let Some(p) = prev {
+                if !p.is_allocated() && !c.is_allocated() &&
+                   p.size() + c.size() <= Chunk::max_size() {
+                    return Err(HeapError::AdjacentFreeChunks)
+                }
+            }
+
+            match c.next() {
+                Some(n) => {
+                    prev = Some(c);
+                    c = n;
+                }
+                None => break
+            }
+        }
+
+        if count != self.chunk_count {
+            return Err(HeapError::ChunkCountMismatch)
+        }
+
+        Ok(())
+    }
+
+    pub fn allocate(&mut self, size: usize) -> Option<*mut u8> {
+        let csize = max(Chunk::to_csize(size), Chunk::min_size());
+
+        // small requests hit the designated victim first, avoiding a bin lookup and a fresh split.
+        if Heap::smallbin_index(csize) < NSMALLBINS && !self.dv.is_null() && self.dvsize >= csize {
+            return Some(self.allocate_from_dv(csize))
+        }
+
+        let c = self.find_chunk(csize);
+        if c.is_null() {
+            return None
+        }
+
+        self.unlink_chunk(c);
+        let c = unsafe { &mut *c };
+        if let Some(rem) = self.split(c, csize) {
+            self.stash_remainder(rem);
+        }
+        c.set_is_allocated(true);
+        Some(self.to_ptr(c))
+    }
+
+    /// Carve `csize` units out of the designated victim, keeping the leftover as the new victim or
+    /// consuming it whole.
+    fn allocate_from_dv(&mut self, csize: usize) -> *mut u8 {
+        let dv = unsafe { &mut *self.dv };
+        match self.split(dv, csize) {
+            Some(rem) => {
+                // the smaller leftover may now be coalescable with a free successor.
+                self.coalesce_forward(rem);
+                self.dv = rem;
+                self.dvsize = rem.size();
+            }
+            None => {
+                self.dv = ptr::null_mut();
+                self.dvsize = 0;
+            }
+        }
+        dv.set_is_allocated(true);
+        self.to_ptr(dv)
+    }
+
+    /// Fold a free, size-compatible successor into `c`. `Heap::new` and the `<= max_size` guard can
+    /// leave adjacent free chunks whose sizes only become coalescable once one of them is split, so
+    /// every freshly freed or split-off region must re-check its successor.
+    fn coalesce_forward(&mut self, c: &mut Chunk) {
+        if let Some(next) = c.next() {
+            if !next.is_allocated() && c.size() + next.size() <= Chunk::max_size() {
+                self.detach_free(next);
+                self.absorb_next(c);
+            }
+        }
+    }
+
+    /// Fold `c` into a free, size-compatible predecessor, returning whichever chunk now owns the
+    /// region.
+    fn coalesce_backward<'b>(&mut self, c: &'b mut Chunk) -> &'b mut Chunk {
+        if let Some(prev) = c.previous() {
+            if !prev.is_allocated() && prev.size() + c.size() <= Chunk::max_size() {
+                self.detach_free(prev);
+                self.absorb_next(prev);
+                return prev
+            }
+        }
+        c
+    }
+
+    /// Return a free chunk to the bins, coalescing it with free neighbours on both sides first.
+    fn bin_free(&mut self, c: &mut Chunk) {
+        self.coalesce_forward(c);
+        let c = self.coalesce_backward(c);
+        self.insert_chunk(c);
+    }
+
+    /// Keep a split remainder as the designated victim, displacing the current one to the bins only
+    /// when the newcomer is larger; otherwise the remainder is binned straight away.
+    fn stash_remainder(&mut self, rem: &mut Chunk) {
+        // the remainder may now be coalescable with its successor even if its parent was not.
+        self.coalesce_forward(rem);
+        if self.dv.is_null() || rem.size() > self.dvsize {
+            if !self.dv.is_null() {
+                let old = self.dv;
+                self.insert_chunk(old);
+            }
+            self.dv = rem;
+            self.dvsize = rem.size();
+        } else {
+            self.insert_chunk(rem);
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must be a pointer previously returned by one of this heap's allocation methods and not
+    /// yet freed; it is cast straight back to a chunk header via `from_ptr`, so a stale or foreign
+    /// pointer is undefined behaviour.
+    pub unsafe fn free(&mut self, ptr: *mut u8) {
+        let c = self.from_ptr(ptr);
+        c.set_is_allocated(false);
+
+        self.coalesce_forward(c);
+        let c = self.coalesce_backward(c);
+
+        self.insert_chunk(c);
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must be a pointer previously returned by one of this heap's allocation methods and not
+    /// yet freed; it is cast straight back to a chunk header via `from_ptr`, so a stale or foreign
+    /// pointer is undefined behaviour.
+    pub unsafe fn reallocate(&mut self, ptr: *mut u8, new_size: usize) -> Option<*mut u8> {
+        let c = self.from_ptr(ptr);
+        let need = max(Chunk::to_csize(new_size), Chunk::min_size());
+
+        // already big enough: hand back the excess and keep the pointer.
+        if need <= c.size() {
+            if let Some(rem) = self.split(c, need) {
+                self.free(self.to_ptr(rem));
+            }
+            return Some(ptr)
+        }
+
+        // grow in place by swallowing a free successor when it is large enough.
+        if let Some(next) = c.next() {
+            if !next.is_allocated() && c.size() + next.size() >= need &&
+               c.size() + next.size() <= Chunk::max_size() {
+                self.detach_free(next);
+                self.absorb_next(c);
+                if let Some(rem) = self.split(c, need) {
+                    self.free(self.to_ptr(rem));
+                }
+                return Some(ptr)
+            }
+        }
+
+        // last resort: move the payload into a fresh chunk and release the old one.
+        let dst = match self.allocate(new_size) {
+            Some(p) => p,
+            None => return None
+        };
+        let payload = (c.size() - Chunk::hdr_csize()) * Chunk::alignment();
+        let count = min(payload, new_size);
+        ptr::copy::<u8>(ptr, dst, count);
+        self.free(ptr);
+        Some(dst)
+    }
+
+    /// Allocate `size` bytes whose payload is aligned to `align`, which may exceed the natural
+    /// `Chunk::alignment()`. Over-aligned requests carve a leading padding chunk so the returned
+    /// pointer lands on an aligned boundary while `from_ptr`/`free` still recover the real header.
+    pub fn allocate_aligned(&mut self, size: usize, align: usize) -> Option<*mut u8> {
+        if align <= Chunk::alignment() {
+            return self.allocate(size)
+        }
+
+        let csize = max(Chunk::to_csize(size), Chunk::min_size());
+        let extra = Chunk::min_size() + Chunk::to_padded_csize(align);
+        let c = self.find_chunk(csize + extra);
+        if c.is_null() {
+            return None
+        }
+        self.unlink_chunk(c);
+        let c = unsafe { &mut *c };
+
+        let unit = Chunk::alignment();
+        let hdr = Chunk::hdr_csize() * unit;
+        let start = c as *const Chunk as usize;
+        // smallest boundary that leaves room for a minimum padding chunk and aligns the payload.
+        let base = start + Chunk::min_size() * unit + hdr;
+        let boundary = ((base + align - 1) & !(align - 1)) - hdr;
+        let pad = (boundary - start) / unit;
+
+        let padding = c as *mut Chunk;
+        let (c, pad_freed) = match self.split(c, pad) {
+            Some(aligned) => (aligned, true),
+            None => (c, false)
+        };
+
+        let rem = self.split(c, csize);
+        // mark the payload taken first so the neighbouring free pieces coalesce *around* it, not
+        // into it, when they re-enter the bins.
+        c.set_is_allocated(true);
+
+        if pad_freed {
+            // the padding chunk stays free; fold it into a free predecessor before binning.
+            let padding = unsafe { &mut *padding };
+            self.bin_free(padding);
+        }
+        if let Some(rem) = rem {
+            // the trailing remainder may now be coalescable with a free successor.
+            self.bin_free(rem);
+        }
+        Some(self.to_ptr(c))
+    }
+
+    // -- segregated free lists -------------------------------------------------------------------
+    //
+    // Free chunks are threaded through an array of bins indexed by size. The 32 small bins hold
+    // chunks of one exact size each, spaced a single alignment unit apart from `Chunk::min_size()`;
+    // everything larger lives in a bitwise digital trie of tree bins keyed on the high bits of the
+    // chunk size. Two `u32` bitmaps record which bins are non-empty so a lookup can jump straight
+    // to the smallest adequate bin with `trailing_zeros` instead of scanning.
+    //
+    // The links are stored in the free chunk's payload: the forward/back pointers of the bin list
+    // at payload slots 0 & 1, and, for tree bins, the two children, parent and tree index at slots
+    // 2..6.
+
+    fn link(&self, c: *mut Chunk, slot: isize) -> *mut Chunk {
+        unsafe { *(self.to_ptr::<*mut Chunk>(&*c).offset(slot)) }
+    }
+    fn set_link(&self, c: *mut Chunk, slot: isize, v: *mut Chunk) {
+        unsafe { *(self.to_ptr::<*mut Chunk>(&*c).offset(slot)) = v }
+    }
+    fn tree_index_of(&self, c: *mut Chunk) -> usize {
+        unsafe { *(self.to_ptr::<usize>(&*c).offset(5)) }
+    }
+    fn set_tree_index_of(&self, c: *mut Chunk, idx: usize) {
+        unsafe { *(self.to_ptr::<usize>(&*c).offset(5)) = idx }
+    }
+
+    fn smallbin_index(csize: usize) -> usize {
+        csize - Chunk::min_size()
+    }
+
+    fn tree_index(csize: usize) -> usize {
+        if csize > 0xFFFF {
+            return NTREEBINS - 1
+        }
+        let bits = usize::BITS as usize;
+        let k = (bits - 1) - (csize.leading_zeros() as usize);
+        ((k << 1) | ((csize >> (k - 1)) & 1)).min(NTREEBINS - 1)
+    }
+
+    fn leftshift_for_tree_index(idx: usize) -> usize {
+        if idx == NTREEBINS - 1 {
+            0
+        } else {
+            (usize::BITS as usize - 1) - ((idx >> 1) + 1)
+        }
+    }
+
+    fn insert_chunk(&mut self, c: *mut Chunk) {
+        let csize = unsafe { (*c).size() };
+        if Heap::smallbin_index(csize) < NSMALLBINS {
+            self.insert_small_chunk(c, csize);
+        } else {
+            self.insert_tree_chunk(c, csize);
+        }
+    }
+
+    /// Remove a free chunk from wherever it is held before it is coalesced away: the designated
+    /// victim lives outside the bins, so it must be cleared rather than unlinked.
+    fn detach_free(&mut self, c: *mut Chunk) {
+        if c == self.dv {
+            self.dv = ptr::null_mut();
+            self.dvsize = 0;
+        } else {
+            self.unlink_chunk(c);
+        }
+    }
+
+    fn unlink_chunk(&mut self, c: *mut Chunk) {
+        let csize = unsafe { (*c).size() };
+        if Heap::smallbin_index(csize) < NSMALLBINS {
+            self.unlink_small_chunk(c, csize);
+        } else {
+            self.unlink_tree_chunk(c);
+        }
+    }
+
+    fn insert_small_chunk(&mut self, c: *mut Chunk, csize: usize) {
+        let idx = Heap::smallbin_index(csize);
+        let head = self.smallbins[idx];
+        if head.is_null() {
+            self.smallmap |= 1 << idx;
+            self.set_link(c, 0, c);
+            self.set_link(c, 1, c);
+        } else {
+            let back = self.link(head, 1);
+            self.set_link(c, 0, head);
+            self.set_link(c, 1, back);
+            self.set_link(back, 0, c);
+            self.set_link(head, 1, c);
+        }
+        self.smallbins[idx] = c;
+    }
+
+    fn unlink_small_chunk(&mut self, c: *mut Chunk, csize: usize) {
+        let idx = Heap::smallbin_index(csize);
+        let fwd = self.link(c, 0);
+        let back = self.link(c, 1);
+        if fwd == c {
+            self.smallbins[idx] = ptr::null_mut();
+            self.smallmap &= !(1 << idx);
+        } else {
+            self.set_link(back, 0, fwd);
+            self.set_link(fwd, 1, back);
+            if self.smallbins[idx] == c {
+                self.smallbins[idx] = fwd;
             }
         }
+    }
+
+    fn insert_tree_chunk(&mut self, c: *mut Chunk, csize: usize) {
+        let idx = Heap::tree_index(csize);
+        self.set_tree_index_of(c, idx);
+        self.set_link(c, 2, ptr::null_mut());
+        self.set_link(c, 3, ptr::null_mut());
+        if (self.treemap & (1 << idx)) == 0 {
+            self.treemap |= 1 << idx;
+            self.treebins[idx] = c;
+            // parent (slot 4) of the root points at the bin slot, flagged by being non-null but
+            // never dereferenced; a null parent marks a same-size list member instead.
+            let bin = &mut self.treebins[idx] as *mut *mut Chunk as *mut Chunk;
+            self.set_link(c, 4, bin);
+            self.set_link(c, 0, c);
+            self.set_link(c, 1, c);
+            return
+        }
 
-        Some(c)
+        let mut t = self.treebins[idx];
+        let mut k = csize << Heap::leftshift_for_tree_index(idx);
+        let bits = usize::BITS as usize;
+        loop {
+            if unsafe { (*t).size() } != csize {
+                let slot = 2 + ((k >> (bits - 1)) & 1) as isize;
+                k <<= 1;
+                let child = self.link(t, slot);
+                if child.is_null() {
+                    self.set_link(t, slot, c);
+                    self.set_link(c, 4, t);
+                    self.set_link(c, 0, c);
+                    self.set_link(c, 1, c);
+                    return
+                }
+                t = child;
+            } else {
+                // same size: splice into `t`'s doubly-linked list, off the tree spine.
+                let fwd = self.link(t, 0);
+                self.set_link(fwd, 1, c);
+                self.set_link(t, 0, c);
+                self.set_link(c, 0, fwd);
+                self.set_link(c, 1, t);
+                self.set_link(c, 4, ptr::null_mut());
+                return
+            }
+        }
     }
+
+    fn unlink_tree_chunk(&mut self, c: *mut Chunk) {
+        let parent = self.link(c, 4);
+        let fwd = self.link(c, 0);
+        let replacement;
+        if fwd != c {
+            // a same-size peer can take `c`'s place in the tree.
+            let back = self.link(c, 1);
+            self.set_link(back, 0, fwd);
+            self.set_link(fwd, 1, back);
+            replacement = fwd;
+        } else {
+            // promote the deepest rightmost descendant.
+            let mut rp = self.link(c, 3);
+            if rp.is_null() {
+                rp = self.link(c, 2);
+            }
+            if rp.is_null() {
+                replacement = ptr::null_mut();
+            } else {
+                let mut cur = rp;
+                loop {
+                    let mut next = self.link(cur, 3);
+                    if next.is_null() {
+                        next = self.link(cur, 2);
+                    }
+                    if next.is_null() {
+                        break
+                    }
+                    cur = next;
+                }
+                // detach `cur` from its parent.
+                let cp = self.link(cur, 4);
+                if self.link(cp, 3) == cur {
+                    self.set_link(cp, 3, ptr::null_mut());
+                } else {
+                    self.set_link(cp, 2, ptr::null_mut());
+                }
+                replacement = cur;
+            }
+        }
+
+        if parent.is_null() {
+            // `c` was only a list member; nothing else to do.
+            return
+        }
+
+        let idx = self.tree_index_of(c);
+        if self.treebins[idx] == c {
+            self.treebins[idx] = replacement;
+            if replacement.is_null() {
+                self.treemap &= !(1 << idx);
+            }
+        } else if self.link(parent, 2) == c {
+            self.set_link(parent, 2, replacement);
+        } else {
+            self.set_link(parent, 3, replacement);
+        }
+
+        if !replacement.is_null() {
+            self.set_tree_index_of(replacement, idx);
+            self.set_link(replacement, 4, parent);
+            let c0 = self.link(c, 2);
+            if !c0.is_null() {
+                self.set_link(replacement, 2, c0);
+                self.set_link(c0, 4, replacement);
+            }
+            let c1 = self.link(c, 3);
+            if !c1.is_null() {
+                self.set_link(replacement, 3, c1);
+                self.set_link(c1, 4, replacement);
+            }
+        }
+    }
+
+    /// Locate the smallest free chunk able to hold `csize` alignment units, consulting the bitmaps
+    /// to skip straight to a non-empty bin. Returns a null pointer when no bin holds an adequate
+    /// chunk; the designated victim is not in the bins and is not searched.
+    fn find_chunk(&mut self, csize: usize) -> *mut Chunk {
+        if Heap::smallbin_index(csize) < NSMALLBINS {
+            let idx = Heap::smallbin_index(csize);
+            let mask = self.smallmap & (!0u32 << idx);
+            if mask != 0 {
+                let i = mask.trailing_zeros() as usize;
+                return self.smallbins[i];
+            }
+        }
+        self.find_tree_chunk(csize)
+    }
+
+    fn find_tree_chunk(&mut self, csize: usize) -> *mut Chunk {
+        let bits = usize::BITS as usize;
+        let idx = Heap::tree_index(csize);
+        let mut v: *mut Chunk = ptr::null_mut();
+        let mut rsize = usize::MAX;
+
+        let mut t = self.treebins[idx];
+        if !t.is_null() {
+            let mut sizebits = csize << Heap::leftshift_for_tree_index(idx);
+            let mut rst: *mut Chunk = ptr::null_mut();
+            while !t.is_null() {
+                let tsize = unsafe { (*t).size() };
+                if tsize >= csize && tsize - csize < rsize {
+                    rsize = tsize - csize;
+                    v = t;
+                    if rsize == 0 {
+                        break
+                    }
+                }
+                let rt = self.link(t, 3);
+                let slot = 2 + ((sizebits >> (bits - 1)) & 1) as isize;
+                t = self.link(t, slot);
+                if !rt.is_null() && rt != t {
+                    rst = rt;
+                }
+                if t.is_null() {
+                    t = rst;
+                    break
+                }
+                sizebits <<= 1;
+            }
+        }
+
+        if t.is_null() && v.is_null() {
+            // nothing in the starting tree: jump to the next populated tree bin.
+            let above = self.treemap & !((1u32 << idx).wrapping_sub(1) | (1u32 << idx));
+            if above != 0 {
+                let i = above.trailing_zeros() as usize;
+                t = self.treebins[i];
+            }
+        }
+
+        // descend to the smallest adequate chunk in whichever subtree we landed on.
+        while !t.is_null() {
+            let tsize = unsafe { (*t).size() };
+            if tsize >= csize && tsize - csize < rsize {
+                rsize = tsize - csize;
+                v = t;
+            }
+            let mut next = self.link(t, 2);
+            if next.is_null() {
+                next = self.link(t, 3);
+            }
+            t = next;
+        }
+
+        v
+    }
+}
+
+/// Supplies the interior mutability the `GlobalAlloc` wrapper needs: the user wraps a `Heap` in
+/// whatever lock fits their target (a spin mutex, a `RefCell` behind a critical section, ...) and
+/// hands out short-lived exclusive access through `with`.
+pub trait HeapGuard {
+    fn with<R, F: FnOnce(&mut Heap) -> R>(&self, f: F) -> R;
+}
+
+/// Thin adapter turning any [`HeapGuard`] into a `#[global_allocator]`.
+pub struct Allocator<G>(pub G);
+
+unsafe impl<G: HeapGuard> GlobalAlloc for Allocator<G> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.0.with(|h| match h.allocate_aligned(layout.size(), layout.align()) {
+            Some(ptr) => ptr,
+            None => ptr::null_mut()
+        })
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+        self.0.with(|h| h.free(ptr));
+    }
+}
+
+/// A single region yielded while walking the heap with [`Heap::chunks`].
+#[derive(Debug, PartialEq)]
+pub struct ChunkInfo {
+    /// chunk size including the header, in alignment units.
+    pub size: usize,
+    /// whether the region is currently handed out to a caller.
+    pub is_allocated: bool,
+    /// pointer to the chunk's payload.
+    pub payload: *mut u8
+}
+
+/// Iterator over the boundary-tag chain returned by [`Heap::chunks`].
+pub struct Chunks {
+    current: *mut Chunk,
+    done: bool
+}
+
+impl Iterator for Chunks {
+    type Item = ChunkInfo;
+
+    fn next(&mut self) -> Option<ChunkInfo> {
+        if self.done {
+            return None
+        }
+
+        let c = unsafe { &*self.current };
+        let payload = unsafe {
+            (self.current as *const usize).offset(Chunk::hdr_csize() as isize) as *mut u8
+        };
+        let info = ChunkInfo {
+            size: c.size(),
+            is_allocated: c.is_allocated(),
+            payload: payload
+        };
+
+        match c.next() {
+            Some(n) => self.current = n,
+            None => self.done = true
+        }
+
+        Some(info)
+    }
+}
+
+/// Invariant violations reported by [`Heap::validate`].
+#[derive(Debug, PartialEq)]
+pub enum HeapError {
+    /// a chunk's `prev_size` disagrees with the actual size of the preceding chunk.
+    PrevSizeMismatch,
+    /// the chain ran past `chunk_count` without reaching a chunk flagged as last.
+    MissingLast,
+    /// the walked length does not match the recorded `chunk_count`.
+    ChunkCountMismatch,
+    /// two coalescable free chunks sit next to each other, i.e. a merge was missed.
+    AdjacentFreeChunks
 }
@@ -3,7 +3,7 @@ extern crate core;
 
 use core::slice;
 use core::cmp::min;
-use silica_chunks::{Heap, Chunk, MIN_PAYLOAD_LEN};
+use silica_chunks::{Heap, Chunk, HeapError, MIN_PAYLOAD_LEN};
 
 
 /// defines a work load of a bit more than 10MiB
@@ -402,3 +402,152 @@ fn test_init() {
         }
     }
 }
+
+#[test]
+fn test_allocate_free_roundtrip() {
+    let mut vec = setup(32*1024);
+    let mut v = vec.as_mut_slice();
+    let mut h = Heap::new(&mut v);
+    let before = h.chunk_count();
+
+    let p = h.allocate(100).unwrap();
+    assert_eq!(Ok(()), h.validate());
+    // the returned payload is usable and aligned to the natural unit.
+    unsafe { *p = 0xAB; }
+    assert_eq!(0, (p as usize) % Chunk::alignment());
+
+    unsafe { h.free(p); }
+    // freeing the lone allocation folds everything back into a single free chunk.
+    assert_eq!(before, h.chunk_count());
+    assert_eq!(Ok(()), h.validate());
+}
+
+#[test]
+fn test_free_coalesces_both_sides() {
+    let mut vec = setup(32*1024);
+    let mut v = vec.as_mut_slice();
+    let mut h = Heap::new(&mut v);
+    let before = h.chunk_count();
+
+    let a = h.allocate(64).unwrap();
+    let b = h.allocate(64).unwrap();
+    assert_eq!(Ok(()), h.validate());
+
+    unsafe { h.free(a); }
+    unsafe { h.free(b); }
+    // forward- and backward-coalescing must rejoin the whole arena.
+    assert_eq!(before, h.chunk_count());
+    assert_eq!(Ok(()), h.validate());
+}
+
+#[test]
+fn test_reallocate_in_place() {
+    let mut vec = setup(64*1024);
+    let mut v = vec.as_mut_slice();
+    let mut h = Heap::new(&mut v);
+
+    let p = h.allocate(64).unwrap();
+    unsafe { *p = 42; }
+
+    // grow into the free successor: pointer is preserved and payload survives.
+    let q = unsafe { h.reallocate(p, 256).unwrap() };
+    assert_eq!(p, q);
+    assert_eq!(42, unsafe { *q });
+    assert_eq!(Ok(()), h.validate());
+
+    // shrink: the excess is handed back and the pointer stays put.
+    let r = unsafe { h.reallocate(q, 32).unwrap() };
+    assert_eq!(q, r);
+    assert_eq!(Ok(()), h.validate());
+
+    unsafe { h.free(r); }
+    assert_eq!(Ok(()), h.validate());
+}
+
+#[test]
+fn test_allocate_aligned() {
+    let mut vec = setup(512*1024);
+    let mut v = vec.as_mut_slice();
+    let mut h = Heap::new(&mut v);
+
+    let mut live = Vec::new();
+    for shift in 3..12 {
+        let align = 1usize << shift;
+        if let Some(p) = h.allocate_aligned(64, align) {
+            assert_eq!(0, (p as usize) % align);
+            live.push(p);
+        }
+        assert_eq!(Ok(()), h.validate());
+    }
+
+    for p in live {
+        unsafe { h.free(p); }
+    }
+    assert_eq!(Ok(()), h.validate());
+}
+
+#[test]
+fn test_chunks_walk() {
+    let mut vec = setup(32*1024);
+    let mut v = vec.as_mut_slice();
+    let mut h = Heap::new(&mut v);
+
+    let p = h.allocate(100).unwrap();
+
+    // the walk length agrees with the bookkeeping counter.
+    assert_eq!(h.chunk_count(), h.chunks().count());
+
+    // exactly one region is handed out, and its payload is the pointer we got back.
+    let allocated: Vec<_> = h.chunks().filter(|c| c.is_allocated).collect();
+    assert_eq!(1, allocated.len());
+    assert_eq!(p, allocated[0].payload);
+
+    unsafe { h.free(p); }
+    assert_eq!(Ok(()), h.validate());
+}
+
+#[test]
+fn test_stress_mixed_validates() {
+    // exercises the allocate/free/reallocate lifecycle against `validate` after every step, the
+    // shape of workload that surfaces missed coalesces on a heap carved into several max chunks.
+    let mut vec = setup(512*1024);
+    let mut v = vec.as_mut_slice();
+    let mut h = Heap::new(&mut v);
+
+    let mut live: Vec<(*mut u8, usize)> = Vec::new();
+    let mut state: u32 = 0x1234_5678;
+    let mut rng = || {
+        state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+        state
+    };
+
+    for _ in 0..4000 {
+        let op = rng() % 3;
+        if op == 0 || live.is_empty() {
+            let size = (rng() as usize % 4096) + 1;
+            if let Some(p) = h.allocate(size) {
+                unsafe { *p = (size & 0xff) as u8; }
+                live.push((p, size));
+            }
+        } else if op == 1 {
+            let i = rng() as usize % live.len();
+            let (p, size) = live.swap_remove(i);
+            assert_eq!((size & 0xff) as u8, unsafe { *p });
+            unsafe { h.free(p); }
+        } else {
+            let i = rng() as usize % live.len();
+            let (p, _) = live[i];
+            let size = (rng() as usize % 8192) + 1;
+            if let Some(np) = unsafe { h.reallocate(p, size) } {
+                unsafe { *np = (size & 0xff) as u8; }
+                live[i] = (np, size);
+            }
+        }
+        assert_eq!(Ok(()), h.validate());
+    }
+
+    for (p, _) in live {
+        unsafe { h.free(p); }
+    }
+    assert_eq!(Ok::<(), HeapError>(()), h.validate());
+}